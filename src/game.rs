@@ -160,11 +160,20 @@ impl Tube {
     }
 }
 
+/// Canonical, order-independent encoding of a [`GameState`], suitable for use as a `HashSet` key.
+pub type StateKey = Vec<u8>;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GameState {
     tubes: Vec<Tube>,
 }
 
+impl Hash for GameState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encode().hash(state);
+    }
+}
+
 impl Display for GameState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let tube_cap = self.tubes[0].capacity;
@@ -286,6 +295,48 @@ impl GameState {
         self.tubes.iter().map(|x| x.entropy()).sum() // TODO: Check if sum or average is better
     }
 
+    /// Packs the state into a canonical [`StateKey`] for O(1) visited-set lookups.
+    ///
+    /// Each distinct [`Color`] is mapped to a small palette index (1-based, with 0 reserved for
+    /// an empty slot) and every tube is serialized as a fixed-width run of those indices. The
+    /// per-tube byte runs are then sorted before being concatenated, so two states that differ
+    /// only by the order of interchangeable tubes collapse to the same key.
+    pub fn encode(&self) -> StateKey {
+        let mut palette: Vec<&Color> = Vec::new();
+        for tube in &self.tubes {
+            for color in &tube.content {
+                if !palette.contains(&color) {
+                    palette.push(color);
+                }
+            }
+        }
+        palette.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut tube_keys: Vec<Vec<u8>> = self
+            .tubes
+            .iter()
+            .map(|tube| {
+                let mut bytes: Vec<u8> = tube
+                    .content
+                    .iter()
+                    .map(|color| {
+                        let index = palette
+                            .iter()
+                            .position(|&x| x == color)
+                            .expect("color was collected into the palette above");
+                        index as u8 + 1
+                    })
+                    .collect();
+                bytes.resize(tube.capacity, 0);
+                bytes
+            })
+            .collect();
+
+        tube_keys.sort_unstable();
+
+        tube_keys.into_iter().flatten().collect()
+    }
+
     pub fn _avg_entropy(&self) -> f64 {
         let total_entropy = self.entropy();
         total_entropy / self.tubes.len() as f64
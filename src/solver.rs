@@ -1,9 +1,9 @@
 use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, HashSet};
 
 use indicatif::{ProgressBar, ProgressStyle};
 
-use crate::game::GameState;
+use crate::game::{GameState, StateKey};
 
 struct QueueElement {
     moves: Vec<(usize, usize)>,
@@ -40,7 +40,7 @@ impl Ord for QueueElement {
 }
 
 pub fn solver(game_state: &GameState) -> Vec<(usize, usize)> {
-    let mut visited = Vec::new();
+    let mut visited: HashSet<StateKey> = HashSet::new();
     let mut queue = BinaryHeap::new();
     let mut total_state = 1;
 
@@ -62,7 +62,7 @@ pub fn solver(game_state: &GameState) -> Vec<(usize, usize)> {
         let game_state = element.game_state;
         let prev_moves = element.moves;
 
-        visited.push(game_state.clone());
+        visited.insert(game_state.encode());
 
         // println!("{} {}", game_state.entropy(), prev_moves.len());
 
@@ -78,7 +78,7 @@ pub fn solver(game_state: &GameState) -> Vec<(usize, usize)> {
         for mov in all_moves {
             match game_state.make_move(mov.0, mov.1) {
                 Ok(state) => {
-                    if visited.contains(&state) {
+                    if visited.contains(&state.encode()) {
                         continue;
                     }
 